@@ -0,0 +1,75 @@
+// ────────────────────── CONFIG (Runtime Tunables) ──────────────────────
+// All the knobs that used to be `const` now live in a TOML file so the
+// governor can be recalibrated for a different model/pipeline without a
+// rebuild. Pass `--config path/to/governor.toml` to load one; omit it and
+// the original defaults apply unchanged.
+
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub n: usize,
+    pub hallucination_threshold: f64,
+    pub dt: f64,
+    pub steps: usize,
+    pub damping: f64,
+    pub visualizer: bool,
+    /// Weight of the spectral-entropy metric when `--metric spectral` is
+    /// active: 1.0 replaces the time-domain score outright, lower values
+    /// blend the two. Ignored when the metric is `time`.
+    pub spectral_blend: f64,
+    /// Minimum acceptable gap between the two leading eigenvalues when
+    /// `--metric gap` is active. Below this, too many modes are competing.
+    pub gap_cutoff: f64,
+    /// How strongly incoming energy couples to whatever's already resonating
+    /// at a site, in `--context` mode.
+    pub context_tension: f64,
+    /// Decay length (in lines) for old context in `--context` mode: each new
+    /// line scales the residual field by `1 - 1/context_window`, so older
+    /// lines fade geometrically rather than dropping out at a hard cutoff.
+    pub context_window: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            n: 80,
+            hallucination_threshold: 0.618,
+            dt: 0.108,
+            steps: 70,
+            damping: 0.991,
+            visualizer: true,
+            spectral_blend: 1.0,
+            gap_cutoff: 0.05,
+            context_tension: 0.3,
+            context_window: 5,
+        }
+    }
+}
+
+impl Config {
+    /// Loads and validates a config file, falling back to nothing — callers
+    /// decide whether a missing/bad file is fatal or should fall back to defaults.
+    pub fn load(path: &str) -> Result<Self, String> {
+        let raw = std::fs::read_to_string(path)
+            .map_err(|e| format!("cannot read config {path}: {e}"))?;
+        let cfg: Config =
+            toml::from_str(&raw).map_err(|e| format!("cannot parse config {path}: {e}"))?;
+        cfg.validate()?;
+        Ok(cfg)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.n < 2 {
+            return Err("n must be >= 2".into());
+        }
+        if !(self.hallucination_threshold > 0.0 && self.hallucination_threshold < 1.0) {
+            return Err("hallucination_threshold must be in (0, 1)".into());
+        }
+        if self.dt <= 0.0 {
+            return Err("dt must be > 0".into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+// ────────────────────── EXPORT (Batch Scoring & Field Rendering) ──────────────────────
+// Headless paths for offline calibration and diagnostics: `--batch` scores a
+// whole file line-by-line without opening the visualizer window, and
+// `--render-field` dumps the raw N×N amplitude field as a false-color image.
+
+use crate::packing::Packing;
+use crate::{Config, Metric, Potential, ResonantLattice};
+use std::io::Write as _;
+
+/// Runs the physics on each line of `input_path` and writes
+/// `line_index,score,verdict` rows to `out_path`, where `score` is whatever
+/// the selected `metric` produces (entropy, blended entropy, or gap).
+#[allow(clippy::too_many_arguments)]
+pub fn run_batch(
+    input_path: &str,
+    out_path: &str,
+    cfg: &Config,
+    potential: Potential,
+    metric: Metric,
+    packing: Packing,
+    seed: Option<u64>,
+    context_mode: bool,
+    render_field: Option<&str>,
+) -> Result<(), String> {
+    let text = std::fs::read_to_string(input_path)
+        .map_err(|e| format!("cannot read {input_path}: {e}"))?;
+    let mut out =
+        std::fs::File::create(out_path).map_err(|e| format!("cannot create {out_path}: {e}"))?;
+    writeln!(out, "line_index,score,verdict").map_err(|e| e.to_string())?;
+
+    let mut lattice = ResonantLattice::new(cfg.clone(), potential, packing, seed);
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if context_mode {
+            lattice.inject_context(line);
+        } else {
+            lattice.inject(line);
+        }
+        for _ in 0..cfg.steps {
+            lattice.step();
+        }
+
+        let (score, blocked, _label) = lattice.verdict(metric);
+        let verdict = if blocked { "BLOCKED" } else { "VERIFIED" };
+        writeln!(out, "{i},{score:.6},{verdict}").map_err(|e| e.to_string())?;
+    }
+
+    if let Some(path) = render_field {
+        render_field_image(&lattice.amplitudes(), cfg.n, path)?;
+    }
+    Ok(())
+}
+
+/// Renders an N×N amplitude field to `out_path`, upsampled and colored with
+/// a viridis-style colormap: cool for coherent regions, warm for
+/// high-amplitude dissonant ones.
+pub fn render_field(amplitudes: &[f64], n: usize, out_path: &str) -> Result<(), String> {
+    render_field_image(amplitudes, n, out_path)
+}
+
+const UPSAMPLE: usize = 6;
+
+fn render_field_image(amplitudes: &[f64], n: usize, out_path: &str) -> Result<(), String> {
+    if amplitudes.len() != n * n {
+        return Err(format!(
+            "amplitude field has {} sites, expected {}",
+            amplitudes.len(),
+            n * n
+        ));
+    }
+
+    let mean = amplitudes.iter().sum::<f64>() / amplitudes.len() as f64;
+    let variance =
+        amplitudes.iter().map(|a| (a - mean).powi(2)).sum::<f64>() / amplitudes.len() as f64;
+    let std = variance.sqrt().max(1e-9);
+
+    let side = n * UPSAMPLE;
+    let mut img = image::RgbImage::new(side as u32, side as u32);
+    for y in 0..side {
+        for x in 0..side {
+            let (sx, sy) = (x / UPSAMPLE, y / UPSAMPLE);
+            let a = amplitudes[sy * n + sx];
+            // Normalize by mean/std, clamp to ±3σ, map into [0, 1].
+            let z = ((a - mean) / std).clamp(-3.0, 3.0);
+            let t = (z + 3.0) / 6.0;
+            let [r, g, b] = viridis(t);
+            img.put_pixel(x as u32, y as u32, image::Rgb([r, g, b]));
+        }
+    }
+    img.save(out_path)
+        .map_err(|e| format!("cannot write {out_path}: {e}"))
+}
+
+/// A small fixed set of viridis control points, linearly interpolated.
+fn viridis(t: f64) -> [u8; 3] {
+    const STOPS: [(f64, [u8; 3]); 5] = [
+        (0.0, [68, 1, 84]),
+        (0.25, [59, 82, 139]),
+        (0.5, [33, 145, 140]),
+        (0.75, [94, 201, 98]),
+        (1.0, [253, 231, 37]),
+    ];
+    let t = t.clamp(0.0, 1.0);
+    for pair in STOPS.windows(2) {
+        let (t0, c0) = pair[0];
+        let (t1, c1) = pair[1];
+        if t <= t1 {
+            let f = if t1 > t0 { (t - t0) / (t1 - t0) } else { 0.0 };
+            return [
+                lerp(c0[0], c1[0], f),
+                lerp(c0[1], c1[1], f),
+                lerp(c0[2], c1[2], f),
+            ];
+        }
+    }
+    STOPS[STOPS.len() - 1].1
+}
+
+fn lerp(a: u8, b: u8, f: f64) -> u8 {
+    (a as f64 + f * (b as f64 - a as f64)) as u8
+}
@@ -10,66 +10,156 @@ use std::time::Duration;
 use num_complex::Complex;
 use minifb::{Key, Window, WindowOptions, Scale};
 
-const N: usize = 80;                     // Lattice size (80x80)
-const HALLUCINATION_THRESHOLD: f64 = 0.618;  // Golden Ratio Cutoff
-const DT: f64 = 0.108;                   // Time step
+mod config;
+mod export;
+mod metrics;
+mod packing;
+mod potential;
+mod rng;
+use config::Config;
+use metrics::Metric;
+use packing::Packing;
+use potential::Potential;
 
-struct ResonantLattice {
+pub(crate) struct ResonantLattice {
     psi: Vec<Complex<f64>>,
     entropy: f64,
+    potential: Potential,
+    packing: Packing,
+    seed: Option<u64>,
+    cfg: Config,
 }
 
 impl ResonantLattice {
-    fn new() -> Self {
+    pub(crate) fn new(cfg: Config, potential: Potential, packing: Packing, seed: Option<u64>) -> Self {
+        let n2 = cfg.n * cfg.n;
         Self {
-            psi: vec![Complex::new(0.0, 0.0); N * N],
+            psi: vec![Complex::new(0.0, 0.0); n2],
             entropy: 0.0,
+            potential,
+            packing,
+            seed,
+            cfg,
         }
     }
 
-    fn inject(&mut self, text: &str) {
+    /// The phase-twist factor for a site: the original fixed 0.61 with no
+    /// seed, or a deterministic counter-based perturbation with one.
+    fn phase_twist(&self, site: usize) -> f64 {
+        self.seed
+            .map(|s| rng::counter_rng(s, site as u64))
+            .unwrap_or(0.61)
+    }
+
+    pub(crate) fn inject(&mut self, text: &str) {
         self.psi.fill(Complex::new(0.0, 0.0));
-        // Map ASCII to Scalar Energy
-        for (i, &b) in text.as_bytes().iter().enumerate().take(N * N) {
+        let n = self.cfg.n;
+        // Map ASCII to Scalar Energy, distributed across sites via `packing`.
+        for (k, &b) in text.as_bytes().iter().enumerate().take(n * n) {
+            let site = self.packing.site_index(k, n);
             let v = b as f64 / 255.0;
             // Inject with a slight phase twist to seed the lattice
-            self.psi[i] = Complex::new(v, v * 0.61); 
+            self.psi[site] = Complex::new(v, v * self.phase_twist(site));
         }
     }
 
-    fn step(&mut self) {
+    /// Like `inject`, but overlays onto the decayed residual of prior lines
+    /// instead of wiping the lattice — a line that's locally plausible but
+    /// contradicts accumulated context raises dissonance instead of starting clean.
+    pub(crate) fn inject_context(&mut self, text: &str) {
+        // Sliding decay window: older context fades roughly over `context_window` lines.
+        let decay = 1.0 - (1.0 / self.cfg.context_window.max(1) as f64);
+        for z in self.psi.iter_mut() {
+            *z *= decay;
+        }
+
+        let n = self.cfg.n;
+        for (k, &b) in text.as_bytes().iter().enumerate().take(n * n) {
+            let site = self.packing.site_index(k, n);
+            let v = b as f64 / 255.0;
+            let incoming = Complex::new(v, v * self.phase_twist(site));
+            let existing = self.psi[site];
+            // Tension couples the incoming energy to whatever's already resonating here.
+            self.psi[site] = existing + incoming * (1.0 + self.cfg.context_tension * existing.norm());
+        }
+    }
+
+    pub(crate) fn step(&mut self) {
         let mut next = self.psi.clone();
         let mut dissonance = 0.0;
-        let size = (N * N) as isize;
+        let n = self.cfg.n;
+        let size = (n * n) as isize;
 
-        for i in 0..N * N {
+        for i in 0..n * n {
             // FIX: Robust Toroidal Wrapping using Euclidean Remainder
             let idx = |d: isize| {
                 ((i as isize + d).rem_euclid(size)) as usize
             };
 
             // Neighbors (Spiral Topology for better mixing)
-            let up    = self.psi[idx(-(N as isize))];
-            let down  = self.psi[idx(N as isize)];
+            let up    = self.psi[idx(-(n as isize))];
+            let down  = self.psi[idx(n as isize)];
             let left  = self.psi[idx(-1)];
             let right = self.psi[idx(1)];
 
             // The Physics: Laplacian Tension - Nonlinear Golden Potential
             let laplacian = up + down + left + right - 4.0 * self.psi[i];
             let mag = self.psi[i].norm();
-            
+
             // This term punishes amplitude spikes (Lies usually spike entropy)
-            let nonlinear = self.psi[i] * (1.0 + 0.618 * mag.powi(2));
+            let nonlinear = self.potential.apply(self.psi[i], mag);
 
             // Symplectic Evolution
-            next[i] += (laplacian - nonlinear) * Complex::i() * DT;
-            next[i] *= 0.991; // Entropy Damping
-            
+            next[i] += (laplacian - nonlinear) * Complex::i() * self.cfg.dt;
+            next[i] *= self.cfg.damping; // Entropy Damping
+
             // Accumulate Imaginary Noise (Dissonance)
             dissonance += next[i].im.abs();
         }
         self.psi = next;
-        self.entropy = (dissonance / (N as f64)).clamp(0.0, 1.0);
+        self.entropy = (dissonance / (n as f64)).clamp(0.0, 1.0);
+    }
+
+    /// Normalized Shannon entropy of the field's 2-D spatial power spectrum.
+    /// See `metrics::spectral_entropy` for the math.
+    fn spectral_entropy(&self) -> f64 {
+        metrics::spectral_entropy(&self.psi, self.cfg.n)
+    }
+
+    /// Gap between the two leading eigenvalues of the linearized operator.
+    /// See `metrics::spectral_gap` for the math.
+    fn spectral_gap(&self) -> f64 {
+        metrics::spectral_gap(&self.psi, self.cfg.n, self.cfg.dt, self.cfg.damping)
+    }
+
+    /// Scores the current field under `metric`, returning `(score, blocked, label)`.
+    /// `blocked` carries the direction check (entropy: high = bad, gap: low = bad)
+    /// so callers stay metric-agnostic.
+    pub(crate) fn verdict(&self, metric: Metric) -> (f64, bool, &'static str) {
+        match metric {
+            Metric::TimeDomain => (self.entropy, self.entropy > self.cfg.hallucination_threshold, "entropy"),
+            Metric::Spectral => {
+                let spectral = self.spectral_entropy();
+                // At full weight, skip the time-domain term entirely instead of
+                // multiplying it by 0.0 — `self.entropy` can be NaN, which would
+                // otherwise poison an otherwise "pure" spectral score.
+                let blended = if self.cfg.spectral_blend >= 1.0 {
+                    spectral
+                } else {
+                    self.cfg.spectral_blend * spectral + (1.0 - self.cfg.spectral_blend) * self.entropy
+                };
+                (blended, blended > self.cfg.hallucination_threshold, "entropy")
+            }
+            Metric::Gap => {
+                let gap = self.spectral_gap();
+                (gap, gap < self.cfg.gap_cutoff, "gap")
+            }
+        }
+    }
+
+    /// Per-site amplitude `|psi|`, for field visualization/export.
+    pub(crate) fn amplitudes(&self) -> Vec<f64> {
+        self.psi.iter().map(|z| z.norm()).collect()
     }
 }
 
@@ -126,17 +216,126 @@ fn visualizer(lattice: Arc<Mutex<ResonantLattice>>) {
 }
 
 // ────────────────────── MAIN ──────────────────────
+/// Scans argv for `--potential <name>`, defaulting to the original golden-cubic term.
+fn parse_potential() -> Potential {
+    let args: Vec<String> = std::env::args().collect();
+    args.iter()
+        .position(|a| a == "--potential")
+        .and_then(|i| args.get(i + 1))
+        .and_then(|name| Potential::from_name(name))
+        .unwrap_or(Potential::GoldenCubic)
+}
+
+/// Scans argv for `--metric <name>`, defaulting to the original time-domain sum.
+fn parse_metric() -> Metric {
+    let args: Vec<String> = std::env::args().collect();
+    args.iter()
+        .position(|a| a == "--metric")
+        .and_then(|i| args.get(i + 1))
+        .and_then(|name| Metric::from_name(name))
+        .unwrap_or(Metric::TimeDomain)
+}
+
+/// Scans argv for the `--context` flag, which keeps context across lines
+/// instead of resetting the lattice on every line.
+fn parse_context() -> bool {
+    std::env::args().any(|a| a == "--context")
+}
+
+/// Scans argv for `--packing <name>`, defaulting to the original row-major layout.
+fn parse_packing() -> Packing {
+    let args: Vec<String> = std::env::args().collect();
+    args.iter()
+        .position(|a| a == "--packing")
+        .and_then(|i| args.get(i + 1))
+        .and_then(|name| Packing::from_name(name))
+        .unwrap_or(Packing::Sc)
+}
+
+/// Scans argv for `--seed <u64>`. Absent, the phase twist uses the
+/// original fixed 0.61 coefficient instead of the counter-based RNG.
+fn parse_seed() -> Option<u64> {
+    let args: Vec<String> = std::env::args().collect();
+    args.iter()
+        .position(|a| a == "--seed")
+        .and_then(|i| args.get(i + 1))
+        .and_then(|s| s.parse::<u64>().ok())
+}
+
+/// Scans argv for `--batch <input> --out <csv>`. Both flags are required
+/// together; a lone `--batch` or `--out` is treated as absent.
+fn parse_batch() -> Option<(String, String)> {
+    let args: Vec<String> = std::env::args().collect();
+    let input = args
+        .iter()
+        .position(|a| a == "--batch")
+        .and_then(|i| args.get(i + 1))
+        .cloned();
+    let out = args
+        .iter()
+        .position(|a| a == "--out")
+        .and_then(|i| args.get(i + 1))
+        .cloned();
+    input.zip(out)
+}
+
+/// Scans argv for `--render-field <path>`, which dumps the amplitude field
+/// as a false-color PNG instead of (or alongside) the visualizer ring.
+fn parse_render_field() -> Option<String> {
+    let args: Vec<String> = std::env::args().collect();
+    args.iter()
+        .position(|a| a == "--render-field")
+        .and_then(|i| args.get(i + 1))
+        .cloned()
+}
+
+/// Scans argv for `--config <path>`, falling back to the built-in defaults
+/// if absent. A present-but-invalid config file is a hard error.
+fn parse_config() -> Config {
+    let args: Vec<String> = std::env::args().collect();
+    match args.iter().position(|a| a == "--config").and_then(|i| args.get(i + 1)) {
+        Some(path) => Config::load(path).unwrap_or_else(|e| {
+            eprintln!("FATAL: {e}");
+            std::process::exit(1);
+        }),
+        None => Config::default(),
+    }
+}
+
 fn main() {
     // Print to Stderr so we don't pollute the pipe
     eprintln!("ONIX GOVERNOR v2.0 — UNIVERSAL FINAL RELEASE");
     eprintln!("Status: Listening on stdin | Pipe any LLM output here");
     eprintln!("─────────────────────────────────────────────────────");
 
-    let lattice = Arc::new(Mutex::new(ResonantLattice::new()));
+    let cfg = parse_config();
+    let potential = parse_potential();
+    let metric = parse_metric();
+    let context_mode = parse_context();
+    let render_field = parse_render_field();
+    let packing = parse_packing();
+    let seed = parse_seed();
+    eprintln!(
+        "Potential: {potential:?}  |  Metric: {metric:?}  |  Context: {context_mode}  |  Packing: {packing:?}  |  Seed: {seed:?}  |  N={} threshold={} steps={}",
+        cfg.n, cfg.hallucination_threshold, cfg.steps
+    );
+
+    // Headless batch mode: score a whole file and exit, no window, no stdin.
+    if let Some((input, out)) = parse_batch() {
+        if let Err(e) = export::run_batch(&input, &out, &cfg, potential, metric, packing, seed, context_mode, render_field.as_deref()) {
+            eprintln!("FATAL: {e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let lattice = Arc::new(Mutex::new(ResonantLattice::new(cfg.clone(), potential, packing, seed)));
     let l_vis = lattice.clone();
-    
+
     // Spawn the Eye
-    thread::spawn(move || visualizer(l_vis));
+    if cfg.visualizer {
+        thread::spawn(move || visualizer(l_vis));
+    }
 
     let stdin = io::stdin();
     // Locking stdin makes it much faster for large text blocks
@@ -149,23 +348,36 @@ fn main() {
         // 1. Run the Physics Check
         {
             let mut l = lattice.lock().unwrap();
-            l.inject(text);
-            // 70 steps gives the wave enough time to find self-interference
-            for _ in 0..70 {
+            if context_mode {
+                l.inject_context(text);
+            } else {
+                l.inject(text);
+            }
+            // `cfg.steps` gives the wave enough time to find self-interference
+            for _ in 0..cfg.steps {
                 l.step();
             }
         }
 
         // 2. The Verdict
-        let entropy = lattice.lock().unwrap().entropy;
+        let (score, blocked, label) = {
+            let l = lattice.lock().unwrap();
+            let verdict = l.verdict(metric);
+            if let Some(path) = &render_field {
+                if let Err(e) = export::render_field(&l.amplitudes(), cfg.n, path) {
+                    eprintln!("WARN: render-field failed: {e}");
+                }
+            }
+            verdict
+        };
 
-        if entropy > HALLUCINATION_THRESHOLD {
+        if blocked {
             // REJECT
-            eprintln!("\x1b[91mBLOCKED\x1b[0m   Hallucination — entropy {entropy:.3} > {HALLUCINATION_THRESHOLD}");
+            eprintln!("\x1b[91mBLOCKED\x1b[0m   Hallucination — {label} {score:.3}");
             // We output nothing to stdout, effectively "silencing" the liar.
         } else {
             // ACCEPT
-            eprintln!("\x1b[92mVERIFIED\x1b[0m  Coherent — entropy {entropy:.3}");
+            eprintln!("\x1b[92mVERIFIED\x1b[0m  Coherent — {label} {score:.3}");
             println!("{text}");
         }
         io::stdout().flush().unwrap();
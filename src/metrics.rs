@@ -0,0 +1,196 @@
+// ────────────────────── METRICS (Alternative Coherence Scores) ──────────────────────
+// The default verdict comes from a time-domain sum of `|Im|` computed inline
+// in `ResonantLattice::step`. This module adds frequency-domain metrics that
+// can replace or blend with it via `--metric`.
+
+use num_complex::Complex;
+use rustfft::FftPlanner;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Metric {
+    /// The original time-domain `|Im|` sum, unchanged.
+    TimeDomain,
+    /// Shannon entropy of the 2-D spatial power spectrum.
+    Spectral,
+    /// Gap between the two leading eigenvalues of the linearized operator.
+    Gap,
+}
+
+impl Metric {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "time" => Some(Metric::TimeDomain),
+            "spectral" => Some(Metric::Spectral),
+            "gap" => Some(Metric::Gap),
+            _ => None,
+        }
+    }
+}
+
+/// Computes the normalized Shannon spectral entropy of an N×N complex field:
+/// FFT it, take the power at each mode, normalize to a distribution, and
+/// measure how spread out that distribution is across `ln(N^2)`.
+///
+/// Coherent text concentrates power in low spatial modes (low H);
+/// hallucinated text smears power across high-frequency modes (high H).
+pub fn spectral_entropy(psi: &[Complex<f64>], n: usize) -> f64 {
+    // A single-site (or empty) field has only one mode, so "spread across
+    // modes" is meaningless — and `ln(n*n)` would be `ln(1) == 0`, dividing
+    // the entropy below by zero.
+    if n <= 1 {
+        return 0.0;
+    }
+    let mut planner = FftPlanner::new();
+    let fft = planner.plan_fft_forward(n);
+    let mut field = psi.to_vec();
+
+    // Row-wise 1-D FFTs.
+    for row in field.chunks_mut(n) {
+        fft.process(row);
+    }
+    // Column-wise 1-D FFTs (gather, transform, scatter — psi is row-major).
+    let mut col = vec![Complex::new(0.0, 0.0); n];
+    for c in 0..n {
+        for r in 0..n {
+            col[r] = field[r * n + c];
+        }
+        fft.process(&mut col);
+        for r in 0..n {
+            field[r * n + c] = col[r];
+        }
+    }
+
+    let power: Vec<f64> = field.iter().map(|z| z.norm_sqr()).collect();
+    let total: f64 = power.iter().sum();
+    if total <= 0.0 {
+        return 0.0;
+    }
+
+    let mut h = 0.0;
+    for &p in &power {
+        if p > 0.0 {
+            let pk = p / total;
+            h -= pk * pk.ln();
+        }
+    }
+    h / ((n * n) as f64).ln()
+}
+
+const SUBSPACE_ITERATIONS: usize = 15;
+
+fn dot(a: &[Complex<f64>], b: &[Complex<f64>]) -> Complex<f64> {
+    a.iter().zip(b.iter()).map(|(x, y)| x.conj() * y).sum()
+}
+
+fn norm(v: &[Complex<f64>]) -> f64 {
+    v.iter().map(|z| z.norm_sqr()).sum::<f64>().sqrt()
+}
+
+/// Normalizes `v` in place, returning `false` (and leaving `v` untouched)
+/// if its norm is too small to divide by safely.
+fn normalize(v: &mut [Complex<f64>]) -> bool {
+    let nrm = norm(v);
+    if nrm < 1e-12 {
+        return false;
+    }
+    for z in v.iter_mut() {
+        *z /= nrm;
+    }
+    true
+}
+
+/// Subtracts `w`'s component along the (already unit-norm) `basis` vector.
+fn orthogonalize(w: &mut [Complex<f64>], basis: &[Complex<f64>]) {
+    let proj = dot(basis, w);
+    for (wi, bi) in w.iter_mut().zip(basis.iter()) {
+        *wi -= bi * proj;
+    }
+}
+
+/// Estimates the spectral gap of the linearized evolution operator — one
+/// step of Laplacian-minus-linear-part, times `i*dt`, times damping, acting
+/// on the flattened field — via subspace (simultaneous) iteration: track a
+/// 2-D basis under repeated application of `A`, re-orthogonalizing each
+/// round, then read off the two leading eigenvalues from the small 2x2
+/// Rayleigh-Ritz projection of `A` onto that basis. This is valid for a
+/// non-Hermitian operator in a way that naive Wielandt deflation (projecting
+/// out `v1` using its own conjugate) is not, since it never assumes the
+/// eigenvectors are orthogonal.
+///
+/// A large gap means one coherent mode dominates (truthful, low entropy); a
+/// small gap means many competing modes (hallucination).
+pub fn spectral_gap(psi: &[Complex<f64>], n: usize, dt: f64, damping: f64) -> f64 {
+    let size = n * n;
+    if size == 0 {
+        return 0.0;
+    }
+    let size_i = size as isize;
+
+    // The linear part is the golden-cubic coefficient `1 + 0.618|psi|^2`
+    // evaluated at the field being judged (`psi`), whichever `--potential`
+    // drives `step`, so the operator — and the resulting gap — actually
+    // depends on the text under test instead of being a fixed constant.
+    let local_weight: Vec<f64> = psi.iter().map(|z| 1.0 + 0.618 * z.norm_sqr()).collect();
+
+    let apply_a = |v: &[Complex<f64>]| -> Vec<Complex<f64>> {
+        let mut out = vec![Complex::new(0.0, 0.0); size];
+        for i in 0..size {
+            let idx = |d: isize| ((i as isize + d).rem_euclid(size_i)) as usize;
+            let up = v[idx(-(n as isize))];
+            let down = v[idx(n as isize)];
+            let left = v[idx(-1)];
+            let right = v[idx(1)];
+            let laplacian = up + down + left + right - 4.0 * v[i];
+            let linear = v[i] * local_weight[i];
+            out[i] = (laplacian - linear) * Complex::i() * dt * damping;
+        }
+        out
+    };
+
+    // Seed the basis from the field itself (and its conjugate) rather than
+    // the uniform all-ones vector, which is exactly the Laplacian's
+    // zero-frequency eigenvector and would never move off it. Conjugation is
+    // antilinear, not a scalar multiple, so v2 starts genuinely independent
+    // of v1 — unlike a phase-rotated copy (`i * psi`), which is just `i`
+    // times v1 and collapses to zero the moment it's orthogonalized.
+    let mut v1 = psi.to_vec();
+    if !normalize(&mut v1) {
+        return 0.0;
+    }
+    let mut v2: Vec<Complex<f64>> = psi.iter().map(|z| z.conj()).collect();
+    orthogonalize(&mut v2, &v1);
+    if !normalize(&mut v2) {
+        return 0.0;
+    }
+
+    for _ in 0..SUBSPACE_ITERATIONS {
+        let mut w1 = apply_a(&v1);
+        if !normalize(&mut w1) {
+            return 0.0;
+        }
+        let mut w2 = apply_a(&v2);
+        orthogonalize(&mut w2, &w1);
+        if !normalize(&mut w2) {
+            return 0.0;
+        }
+        v1 = w1;
+        v2 = w2;
+    }
+
+    // Rayleigh-Ritz: project A onto the converged 2-D basis and read its
+    // eigenvalues off the resulting 2x2 matrix via the quadratic formula.
+    let a1 = apply_a(&v1);
+    let a2 = apply_a(&v2);
+    let m11 = dot(&v1, &a1);
+    let m12 = dot(&v1, &a2);
+    let m21 = dot(&v2, &a1);
+    let m22 = dot(&v2, &a2);
+
+    let trace = m11 + m22;
+    let det = m11 * m22 - m12 * m21;
+    let discriminant = (trace * trace - det * 4.0).sqrt();
+    let lambda1 = (trace + discriminant) / 2.0;
+    let lambda2 = (trace - discriminant) / 2.0;
+
+    (lambda1.norm() - lambda2.norm()).abs()
+}
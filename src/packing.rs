@@ -0,0 +1,68 @@
+// ────────────────────── PACKING (Lattice Site Layouts for Injection) ──────────────────────
+// Controls how injected bytes are distributed across the N×N grid. `Sc`
+// (simple cubic) is the original row-major mapping; `Bcc`/`Fcc` stagger
+// bytes across interleaved sublattices for better mixing, echoing how
+// those crystal packings add corner + body/face-centered sites in 3-D.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Packing {
+    Sc,
+    Bcc,
+    Fcc,
+}
+
+impl Packing {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "sc" => Some(Packing::Sc),
+            "bcc" => Some(Packing::Bcc),
+            "fcc" => Some(Packing::Fcc),
+            _ => None,
+        }
+    }
+
+    /// Maps the `k`-th injected byte to a lattice site index in an N×N grid.
+    pub fn site_index(&self, k: usize, n: usize) -> usize {
+        if n == 0 {
+            return 0;
+        }
+        match self {
+            Packing::Sc => k % (n * n),
+
+            Packing::Bcc => {
+                // Two sublattices: the plain row-major grid, and a copy
+                // staggered by half a row and half a column.
+                let phase = k % 2;
+                let (row, col) = base_coords(k / 2, n);
+                let (row, col) = if phase == 1 {
+                    ((row + n / 2) % n, (col + n / 2) % n)
+                } else {
+                    (row, col)
+                };
+                row * n + col
+            }
+
+            Packing::Fcc => {
+                // Four sublattices: the corner grid plus the three
+                // face-center offsets (row-shifted, col-shifted, both).
+                // The corner grid is half the grid on a side, so the four
+                // copies tile the grid without landing on each other.
+                let phase = k % 4;
+                let half = (n / 2).max(1);
+                let j = k / 4;
+                let (row, col) = ((j / half) % n, j % half);
+                let (dr, dc) = match phase {
+                    0 => (0, 0),
+                    1 => (n / 2, 0),
+                    2 => (0, n / 2),
+                    _ => (n / 2, n / 2),
+                };
+                ((row + dr) % n) * n + ((col + dc) % n)
+            }
+        }
+    }
+}
+
+fn base_coords(j: usize, n: usize) -> (usize, usize) {
+    ((j / n) % n, j % n)
+}
@@ -0,0 +1,68 @@
+// ────────────────────── POTENTIAL (Chaotic Map Selection) ──────────────────────
+// The lattice punishes amplitude spikes with a nonlinear term each step.
+// `Potential` selects which chaotic dynamics generate that punishment, so
+// users can compare how aggressively each one rejects runaway energy.
+
+use num_complex::Complex;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Potential {
+    /// Original term: psi * (1 + 0.618 * |psi|^2)
+    GoldenCubic,
+    /// Logistic map coupling: f(m) = r * m * (1 - m), chaotic for r ~ 3.57..4.0
+    Logistic { r: f64 },
+    /// Henon-style two-component map applied directly to (re, im)
+    Henon { a: f64, b: f64 },
+    /// Lorenz attractor integrated per site; z is injected as a phase kick
+    Lorenz { sigma: f64, rho: f64, beta: f64 },
+}
+
+impl Potential {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "golden" => Some(Potential::GoldenCubic),
+            "logistic" => Some(Potential::Logistic { r: 3.7 }),
+            "henon" => Some(Potential::Henon { a: 1.4, b: 0.3 }),
+            "lorenz" => Some(Potential::Lorenz {
+                sigma: 10.0,
+                rho: 28.0,
+                beta: 8.0 / 3.0,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Computes the nonlinear term for one site, used in place of the
+    /// hardcoded `nonlinear` term in `ResonantLattice::step`.
+    pub fn apply(&self, site: Complex<f64>, mag: f64) -> Complex<f64> {
+        match *self {
+            Potential::GoldenCubic => site * (1.0 + 0.618 * mag.powi(2)),
+
+            Potential::Logistic { r } => {
+                // Clamp into [0, 1] — the logistic map only stays bounded there.
+                let m = mag.clamp(0.0, 1.0);
+                site * (1.0 + r * m * (1.0 - m))
+            }
+
+            Potential::Henon { a, b } => {
+                let (re, im) = (site.re, site.im);
+                Complex::new(1.0 - a * re * re + im, b * re)
+            }
+
+            Potential::Lorenz { sigma, rho, beta } => {
+                // One Euler step of the full Lorenz system, treating (re, im, mag)
+                // as (x, y, z): x and y advance the site directly, and the
+                // resulting z drives a phase kick on top of that.
+                const LORENZ_DT: f64 = 0.01;
+                let (x, y, z) = (site.re, site.im, mag);
+                let dx = sigma * (y - x);
+                let dy = x * (rho - z) - y;
+                let dz = x * y - beta * z;
+                let x_next = x + dx * LORENZ_DT;
+                let y_next = y + dy * LORENZ_DT;
+                let z_next = z + dz * LORENZ_DT;
+                Complex::new(x_next, y_next) * Complex::from_polar(1.0, z_next * 0.1)
+            }
+        }
+    }
+}
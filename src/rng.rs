@@ -0,0 +1,19 @@
+// ────────────────────── RNG (Counter-Based, Threefry-Style) ──────────────────────
+// No sequential state: each site's perturbation is a pure function of
+// (seed, index), so the same text + seed always yields identical entropy,
+// and sites can be generated in any order or in parallel.
+
+/// Mixes `seed` and `index` through a few multiply-xorshift rounds, in the
+/// spirit of a threefry counter-based generator. Returns a value in [0, 1).
+pub fn counter_rng(seed: u64, index: u64) -> f64 {
+    let mut x = seed ^ index.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    for _ in 0..4 {
+        x ^= x >> 30;
+        x = x.wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        x ^= x >> 27;
+        x = x.wrapping_mul(0x94D0_49BB_1331_11EB);
+        x ^= x >> 31;
+        x = x.wrapping_add(index);
+    }
+    (x >> 11) as f64 / (1u64 << 53) as f64
+}